@@ -21,7 +21,7 @@ fn parse_brightness(value: &str) -> Result<Brightness, String> {
     long_about = None
 )]
 pub struct Args {
-    /// Filesystem path to CPU thermal info.
+    /// Filesystem path to CPU thermal info (a `temp` file or a directory to scan).
     #[clap(
         short,
         long,
@@ -32,6 +32,15 @@ pub struct Args {
     /// Network interface name (IPv4 field).
     #[clap(short, long, value_parser, default_value = "wlan0")]
     pub interface: String,
+    /// GPIO pin of a directly-connected DHT22/DHT11 sensor (bypasses stdin).
+    #[clap(short, long, value_parser)]
+    pub sensor: Option<u32>,
+    /// Read measurements from an HTU21D I2C sensor instead of stdin.
+    #[clap(long, value_parser)]
+    pub htu: bool,
+    /// Seconds each page is shown before rotating (0 disables rotation).
+    #[clap(short, long, value_parser, default_value_t = 5)]
+    pub page_interval: u64,
     /// Cpu usage/temperature readings delay in ms (>=100).
     #[clap(
         short,
@@ -3,6 +3,7 @@ use embedded_graphics::{
     mono_font::{ascii::FONT_6X9, MonoTextStyle},
     pixelcolor::BinaryColor,
     prelude::*,
+    primitives::{Line, PrimitiveStyle},
     text::Text,
 };
 use rppal::i2c::I2c;
@@ -50,4 +51,59 @@ impl I2cDisplay {
 
         Ok(())
     }
+
+    /// Draw a `header` line of text above an auto-scaled sparkline of `history`.
+    ///
+    /// The y-axis is scaled to the current min/max of `history` and each sample
+    /// is mapped to a column across the 128-pixel width, letting callers keep a
+    /// fixed-capacity ring buffer of recent samples and render a scrolling graph.
+    pub fn draw_graph(&mut self, header: &str, history: &[f32]) -> Result<()> {
+        // Clear the display buffer.
+        self.disp.clear();
+
+        // Header text occupies the first text row.
+        Text::with_baseline(
+            header,
+            Point::zero(),
+            MonoTextStyle::new(&FONT_6X9, BinaryColor::On),
+            embedded_graphics::text::Baseline::Top,
+        )
+        .draw(&mut self.disp)
+        .map_err(|_| ErrorKind::I2cWriteErr)?;
+
+        // A single sample has nothing to connect; skip the graph region.
+        if history.len() >= 2 {
+            // Graph region: full width, below the header row.
+            const WIDTH: i32 = 128;
+            const TOP: i32 = 12;
+            const BOTTOM: i32 = 63;
+            let graph_height = BOTTOM - TOP;
+
+            let min = history.iter().copied().fold(f32::INFINITY, f32::min);
+            let max = history.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+            let span = (max - min).max(f32::EPSILON);
+            let last = history.len() as i32 - 1;
+
+            let x_of = |i: usize| -> i32 { (i as i32 * (WIDTH - 1)) / last };
+            // Higher values map to a smaller (higher on screen) y coordinate.
+            let y_of = |value: f32| -> i32 {
+                BOTTOM - (((value - min) / span) * graph_height as f32) as i32
+            };
+
+            let style = PrimitiveStyle::with_stroke(BinaryColor::On, 1);
+            for (i, pair) in history.windows(2).enumerate() {
+                Line::new(
+                    Point::new(x_of(i), y_of(pair[0])),
+                    Point::new(x_of(i + 1), y_of(pair[1])),
+                )
+                .into_styled(style)
+                .draw(&mut self.disp)
+                .map_err(|_| ErrorKind::I2cWriteErr)?;
+            }
+        }
+
+        self.disp.flush().map_err(|_| ErrorKind::I2cWriteErr)?;
+
+        Ok(())
+    }
 }
@@ -1,3 +1,4 @@
+use gpio_cdev::Error as GpioError;
 use interfaces::InterfacesError;
 use procfs::ProcError;
 use rppal::i2c::Error as I2cError;
@@ -25,6 +26,14 @@ pub enum ErrorKind {
     I2cWriteErr,
     /// Occurs when unable to access `/proc` filesystem.
     ProcFsErr(ProcError),
+    /// Occurs when unable to access a GPIO line.
+    GpioErr(GpioError),
+    /// Occurs when an HTU21D sensor reading fails its CRC validation.
+    CrcErr,
+    /// Occurs when a DHT sensor reading fails its checksum validation.
+    ChecksumErr,
+    /// Occurs when a sensor does not deliver a full frame before the read timeout.
+    SensorTimeout,
     /// Occurs when unable to open file.
     FileOpenErr(PathBuf, IoError),
     /// Occurs when unable to read from file.
@@ -51,6 +60,10 @@ impl Display for ErrorKind {
             Self::I2cInitErr => write!(f, "unable to initialize I2C display"),
             Self::I2cWriteErr => write!(f, "unable to write to I2C display"),
             Self::ProcFsErr(err) => write!(f, "unable to access '/proc' filesystem: {}", err),
+            Self::GpioErr(err) => write!(f, "unable to access GPIO line: {}", err),
+            Self::CrcErr => write!(f, "sensor reading failed CRC validation"),
+            Self::ChecksumErr => write!(f, "DHT sensor reading failed checksum validation"),
+            Self::SensorTimeout => write!(f, "timed out waiting for a full sensor frame"),
             Self::FileOpenErr(path, err) => {
                 write!(f, "unable to open '{}': {}", path.display(), err)
             }
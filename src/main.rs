@@ -2,6 +2,7 @@ mod args;
 mod display;
 mod error;
 mod measure;
+mod sensor;
 mod sys_info;
 
 use args::{Args, Parser};
@@ -10,6 +11,7 @@ use display::I2cDisplay;
 use error::ErrorKind;
 use interfaces::Interface;
 use measure::Measure;
+use sensor::{DhtSensor, Htu21d};
 use signal_hook::{consts::SIGUSR1, flag::register};
 use std::{
     fmt::{self, Display, Formatter},
@@ -23,7 +25,10 @@ use std::{
     thread,
     time::{Duration, Instant},
 };
-use sys_info::{disk_free, pgrep, Cpu, CpuInfo, InterfaceIPv4, Meminfo, MeminfoPerc};
+use sys_info::{
+    pgrep, Component, Components, Cpu, CpuInfo, Disk, Disks, InterfaceIPv4, Meminfo, MeminfoPerc,
+    Net, NetRate, Process, Processes,
+};
 
 type Result<T> = std::result::Result<T, ErrorKind>;
 
@@ -37,8 +42,22 @@ struct Screen {
     cpu: CpuInfo,
     /// Memory (RAM) information from `/proc/meminfo`.
     mem: Meminfo,
-    /// Free disk space.
-    disk: String,
+    /// Disk space and I/O collector.
+    disks: Disks,
+    /// Latest per-mount disk readings.
+    disk_readings: Vec<Disk>,
+    /// Network throughput sampler.
+    net: Net,
+    /// Latest network RX/TX rates.
+    net_rate: NetRate,
+    /// `hwmon` thermal component collector.
+    components: Components,
+    /// Latest `hwmon` component readings.
+    component_readings: Vec<Component>,
+    /// Per-process resource collector.
+    processes: Processes,
+    /// Latest top processes by CPU usage.
+    top_processes: Vec<Process>,
     /// `datalogger` CSV file printing.
     ///
     /// # Note
@@ -60,8 +79,15 @@ impl Screen {
                 .map_err(ErrorKind::InterfaceErr)?
                 .ok_or_else(|| ErrorKind::InterfaceNotFound(interface.to_string()))?,
             cpu: CpuInfo::default(),
-            mem: Meminfo::new().map_err(ErrorKind::ProcFsErr)?,
-            disk: String::from("--"),
+            mem: Meminfo::new()?,
+            disks: Disks::new(),
+            disk_readings: Vec::new(),
+            net: Net::new(interface),
+            net_rate: NetRate::default(),
+            components: Components::new(),
+            component_readings: Vec::new(),
+            processes: Processes::new(),
+            top_processes: Vec::new(),
             logging: false,
             datalogger: pgrep("datalogger")?,
             bombuscv: pgrep("bombuscv")?,
@@ -80,8 +106,11 @@ impl Screen {
         if let Some(cpu) = cpu {
             self.cpu = cpu;
         };
-        self.mem = Meminfo::new().map_err(ErrorKind::ProcFsErr)?;
-        self.disk = disk_free()?;
+        self.mem.refresh();
+        self.disk_readings = self.disks.refresh();
+        self.net_rate = self.net.rates();
+        self.component_readings = self.components.refresh();
+        self.top_processes = self.processes.top(TOP_PROCESSES)?;
         // If SIGUSR1 is received swap logging status.
         if sigusr1_received {
             self.logging = !self.logging
@@ -93,26 +122,107 @@ impl Screen {
     }
 }
 
+/// Named display pages cycled by the rotation interval.
+const PAGES: [&str; 7] = [
+    "SYSTEM", "NETWORK", "THERMAL", "HWMON", "DISK", "GRAPH", "PROCESSES",
+];
+
+/// Number of CPU-usage samples retained for the scrolling graph.
+const HISTORY_CAP: usize = 128;
+
+/// Number of processes listed on the processes page.
+const TOP_PROCESSES: usize = 5;
+
+impl Screen {
+    /// Number of available pages.
+    fn page_count(&self) -> usize {
+        PAGES.len()
+    }
+
+    /// `hwmon` component readings as `label: xx.xC` lines, flagging any reading
+    /// at or above its critical threshold with a trailing `!`.
+    fn components_summary(&self) -> String {
+        self.component_readings
+            .iter()
+            .map(|component| {
+                format!(
+                    "{}: {:.1}C{}",
+                    component.label,
+                    component.temperature,
+                    if component.is_critical() { "!" } else { "" },
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Available space of the `/` mount in human-readable form.
+    fn root_free(&self) -> String {
+        sys_info::disk_free().unwrap_or_else(|_| String::from("--"))
+    }
+
+    /// Per-mount disk space and I/O as one line each.
+    fn disk_summary(&self) -> String {
+        self.disk_readings
+            .iter()
+            .map(|disk| disk.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Render the lines of the page at `index` (wrapping around).
+    fn page(&self, index: usize) -> String {
+        let timestamp = Local::now().format("%Y-%m-%d %H:%M:%S");
+
+        match PAGES[index % PAGES.len()] {
+            "NETWORK" => format!(
+                "{}\nNETWORK\nIP: {}\n{}\n{}",
+                timestamp,
+                self.interface
+                    .local_ipv4()
+                    .unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED)),
+                self.net_rate,
+                self.net_rate.packets(),
+            ),
+            "THERMAL" => format!(
+                "{}\nTHERMAL\n{}",
+                timestamp,
+                self.cpu.thermal_breakdown(),
+            ),
+            "HWMON" => format!("{}\nHWMON\n{}", timestamp, self.components_summary()),
+            "DISK" => format!("{}\nDISK\n{}", timestamp, self.disk_summary()),
+            "PROCESSES" => format!(
+                "{}\nDATALOGGER: {} BOMBUSCV: {}\n{}",
+                timestamp,
+                if self.datalogger && self.logging {
+                    "logging"
+                } else {
+                    "--"
+                },
+                if self.bombuscv { "running" } else { "--" },
+                self.top_processes
+                    .iter()
+                    .map(|process| process.to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+            // "SYSTEM" and any future page fall back to the overview.
+            _ => format!(
+                "{}\n{}\nCPU: {}\nCORES: {}\nMEM: {:.1}% DISK: {}",
+                timestamp,
+                self.measure,
+                self.cpu,
+                self.cpu.per_core_summary(),
+                self.mem.free_percent(),
+                self.root_free(),
+            ),
+        }
+    }
+}
+
 impl Display for Screen {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}\n{}\nIP: {}\nCPU: {}\nMEM: {:.1}% DISK: {}\nDATALOGGER: {}\nBOMBUSCV: {}",
-            Local::now().format("%Y-%m-%d %H:%M:%S"),
-            self.measure,
-            self.interface
-                .local_ipv4()
-                .unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED)),
-            self.cpu,
-            self.mem.free_percent(),
-            self.disk,
-            if self.datalogger && self.logging {
-                "logging"
-            } else {
-                "--"
-            },
-            if self.bombuscv { "running" } else { "--" },
-        )
+        write!(f, "{}", self.page(0))
     }
 }
 
@@ -125,8 +235,44 @@ fn run(args: Args) -> Result<()> {
     let (tx_measure, rx_measure) = mpsc::channel();
     let (tx_cpu, rx_cpu) = mpsc::channel();
 
-    // Thread handling humidity and temperature data piped to the program.
+    // Thread handling humidity and temperature data.
+    let sensor = args.sensor;
+    let htu = args.htu;
+    let delay = args.delay;
+    let page_interval = args.page_interval;
     let measure_thread = thread::spawn(move || -> Result<()> {
+        // When a `--sensor` pin is given, talk to a directly-connected DHT22/DHT11
+        // over GPIO and poll it on the configured delay instead of reading stdin.
+        if let Some(pin) = sensor {
+            let mut dht = DhtSensor::new(pin)?;
+            loop {
+                match dht.read() {
+                    Ok(measure) => tx_measure
+                        .send(measure)
+                        .map_err(|_| ErrorKind::MsgPassingErr)?,
+                    // DHT frames routinely drop or mis-clock; log the transient
+                    // failure and retry on the next tick rather than tearing down
+                    // the thread (and with it the whole program).
+                    Err(err @ (ErrorKind::ChecksumErr | ErrorKind::SensorTimeout)) => {
+                        eprintln!("warning: {err}, retrying.");
+                    }
+                    Err(err) => return Err(err),
+                }
+                thread::sleep(Duration::from_millis(delay));
+            }
+        }
+
+        // Alternatively poll an HTU21D on the shared I2C bus.
+        if htu {
+            let mut htu = Htu21d::new()?;
+            loop {
+                tx_measure
+                    .send(htu.read()?)
+                    .map_err(|_| ErrorKind::MsgPassingErr)?;
+                thread::sleep(Duration::from_millis(delay));
+            }
+        }
+
         loop {
             // Read data from stdin (used in this case to pipe from datalogger, program).
             // https://github.com/marcoradocchia/datalogger
@@ -165,6 +311,12 @@ fn run(args: Args) -> Result<()> {
     register(SIGUSR1, Arc::clone(&sigusr1))
         .map_err(|_| "unable to register SIGUSR1 event handler")?;
 
+    // Currently displayed page and the instant it became visible.
+    let mut page = 0;
+    let mut page_instant = Instant::now();
+    // Ring buffer of recent CPU-usage samples for the scrolling graph.
+    let mut history: Vec<f32> = Vec::with_capacity(HISTORY_CAP);
+
     // Refresh display at 1Hz.
     loop {
         let instant = Instant::now();
@@ -191,8 +343,23 @@ fn run(args: Args) -> Result<()> {
                 .then(|| sigusr1.store(false, Ordering::Relaxed))
                 .is_some(),
         )?;
+        // Append the latest CPU usage sample, dropping the oldest when full.
+        if history.len() == HISTORY_CAP {
+            history.remove(0);
+        }
+        history.push(screen.cpu.usage());
+
+        // Rotate to the next page once the configured interval has elapsed.
+        if page_interval > 0 && page_instant.elapsed() >= Duration::from_secs(page_interval) {
+            page = (page + 1) % screen.page_count();
+            page_instant = Instant::now();
+        }
         // dbg!(screen.datalogger, screen.logging);
-        i2c_display.refresh_display(&screen.to_string())?;
+        if PAGES[page] == "GRAPH" {
+            i2c_display.draw_graph("CPU %", &history)?;
+        } else {
+            i2c_display.refresh_display(&screen.page(page))?;
+        }
 
         // Sleep for 1 second (1Hz refresh rate) corrected by the time spent measuring: if elapsed
         // time is grates than the specified interval, this means the measuring process took
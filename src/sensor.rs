@@ -0,0 +1,208 @@
+use crate::measure::Measure;
+use crate::{ErrorKind, Result};
+use gpio_cdev::{Chip, EventRequestFlags, EventType, LineRequestFlags};
+use nix::poll::{poll, PollFd, PollFlags};
+use rppal::i2c::I2c;
+use std::os::unix::io::AsRawFd;
+use std::{thread, time::Duration};
+
+/// GPIO character device hosting the sensor line.
+const GPIO_CHIP: &str = "/dev/gpiochip0";
+
+/// I2C address of the HTU21D/HTU2xD sensor.
+const HTU21D_ADDR: u16 = 0x40;
+/// "No-hold" trigger command for a relative-humidity measurement.
+const HTU21D_TRIGGER_HUMIDITY: u8 = 0xF5;
+/// "No-hold" trigger command for a temperature measurement.
+const HTU21D_TRIGGER_TEMPERATURE: u8 = 0xF3;
+
+/// High-pulse width threshold (in nanoseconds) separating a `0` bit (~27µs) from
+/// a `1` bit (~70µs) in the DHT data stream.
+const BIT_THRESHOLD_NS: u64 = 35_000;
+
+/// Maximum time to wait for a full 40-bit frame before giving up on an
+/// unresponsive sensor.
+const READ_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// DHT22/DHT11 one-wire humidity & temperature sensor on a single GPIO line.
+pub struct DhtSensor {
+    chip: Chip,
+    pin: u32,
+}
+
+impl DhtSensor {
+    /// Construct a `DhtSensor` reader bound to the given GPIO `pin`.
+    pub fn new(pin: u32) -> Result<Self> {
+        let chip = Chip::new(GPIO_CHIP).map_err(ErrorKind::GpioErr)?;
+
+        Ok(Self { chip, pin })
+    }
+
+    /// Perform a single read cycle and return the decoded `Measure`.
+    ///
+    /// The host drives the line low for ~18ms as a start signal and releases it;
+    /// the sensor replies with a ~80µs low + ~80µs high preamble followed by 40
+    /// data bits, each a ~50µs low spacer and a high pulse whose width encodes the
+    /// bit value. The high-pulse durations are thresholded back into 40 bits = 5
+    /// bytes `[hum_hi, hum_lo, temp_hi, temp_lo, checksum]`.
+    pub fn read(&mut self) -> Result<Measure> {
+        let line = self.chip.get_line(self.pin).map_err(ErrorKind::GpioErr)?;
+
+        // Start signal: drive the line low for ~18ms, then release it by dropping
+        // the output handle so the pull-up restores the idle-high level.
+        {
+            let handle = line
+                .request(LineRequestFlags::OUTPUT, 0, "bombuscv-display")
+                .map_err(ErrorKind::GpioErr)?;
+            thread::sleep(Duration::from_millis(18));
+            drop(handle);
+        }
+
+        // Capture both-edge timestamps, reducing each rising→falling pair to the
+        // duration the line stayed high.
+        let mut events = line
+            .events(
+                LineRequestFlags::INPUT,
+                EventRequestFlags::BOTH_EDGES,
+                "bombuscv-display",
+            )
+            .map_err(ErrorKind::GpioErr)?;
+
+        // The edge read blocks inside the event fd, so a non-responsive sensor
+        // emits no edges and would hang forever. Poll the fd with the remaining
+        // budget before each read so the deadline actually bites.
+        let fd = events.as_raw_fd();
+        let deadline = std::time::Instant::now() + READ_TIMEOUT;
+        let mut highs: Vec<u64> = Vec::with_capacity(41);
+        let mut rising: Option<u64> = None;
+        // The event queue opens only after the line has settled back high, so the
+        // first edge the kernel reports is the falling edge as the sensor pulls
+        // low: one preamble high pulse followed by the 40 data-bit highs, 41 at
+        // most. Collect edges until we have the full frame or the deadline bites.
+        while highs.len() < 41 {
+            let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+
+            let mut fds = [PollFd::new(fd, PollFlags::POLLIN)];
+            // A zero return means the poll timed out on an idle line.
+            if poll(&mut fds, remaining.as_millis() as i32)
+                .map_err(|err| ErrorKind::Other(format!("unable to poll GPIO line: {}", err)))?
+                == 0
+            {
+                break;
+            }
+
+            let event = events.get_event().map_err(ErrorKind::GpioErr)?;
+            match event.event_type() {
+                EventType::RisingEdge => rising = Some(event.timestamp()),
+                EventType::FallingEdge => {
+                    if let Some(rising) = rising.take() {
+                        highs.push(event.timestamp().saturating_sub(rising));
+                    }
+                }
+            }
+        }
+
+        // Fewer than 40 data-bit highs means the sensor never delivered a full
+        // frame within `READ_TIMEOUT`.
+        if highs.len() < 40 {
+            return Err(ErrorKind::SensorTimeout);
+        }
+
+        // The last 40 high pulses are the data bits; any earlier pulse belongs to
+        // the preamble.
+        let mut bytes = [0u8; 5];
+        for (i, &high) in highs[highs.len() - 40..].iter().enumerate() {
+            if high > BIT_THRESHOLD_NS {
+                bytes[i / 8] |= 1 << (7 - (i % 8));
+            }
+        }
+        let [hum_hi, hum_lo, temp_hi, temp_lo, checksum] = bytes;
+
+        if checksum
+            != hum_hi
+                .wrapping_add(hum_lo)
+                .wrapping_add(temp_hi)
+                .wrapping_add(temp_lo)
+        {
+            return Err(ErrorKind::ChecksumErr);
+        }
+
+        let humidity = (((hum_hi as u16) << 8) | hum_lo as u16) as f32 / 10.0;
+        // Top bit of the temperature high byte carries the sign.
+        let mut temperature =
+            ((((temp_hi & 0x7f) as u16) << 8) | temp_lo as u16) as f32 / 10.0;
+        if temp_hi & 0x80 != 0 {
+            temperature = -temperature;
+        }
+
+        Ok(Measure::new(humidity, temperature))
+    }
+}
+
+/// HTU21D (HTU2xD-family) humidity & temperature sensor on the I2C bus.
+pub struct Htu21d {
+    i2c: I2c,
+}
+
+impl Htu21d {
+    /// Construct an `Htu21d` reader on the shared I2C bus.
+    pub fn new() -> Result<Self> {
+        let mut i2c = I2c::new().map_err(ErrorKind::I2cAccessErr)?;
+        i2c.set_slave_address(HTU21D_ADDR)
+            .map_err(ErrorKind::I2cAccessErr)?;
+
+        Ok(Self { i2c })
+    }
+
+    /// CRC-8 with polynomial `0x31` (x⁸+x⁵+x⁴+1), initial value `0`.
+    fn crc8(data: &[u8]) -> u8 {
+        let mut crc: u8 = 0;
+        for &byte in data {
+            crc ^= byte;
+            for _ in 0..8 {
+                crc = if crc & 0x80 != 0 {
+                    (crc << 1) ^ 0x31
+                } else {
+                    crc << 1
+                };
+            }
+        }
+
+        crc
+    }
+
+    /// Trigger a "no-hold" conversion, read MSB/LSB/CRC, validate the CRC and
+    /// return the 16-bit raw value with the two status bits masked off.
+    fn measure_raw(&mut self, command: u8) -> Result<u16> {
+        self.i2c
+            .write(&[command])
+            .map_err(ErrorKind::I2cAccessErr)?;
+        // Wait for the conversion to complete (~50ms for both measurements).
+        thread::sleep(Duration::from_millis(50));
+
+        let mut buf = [0u8; 3];
+        self.i2c.read(&mut buf).map_err(ErrorKind::I2cAccessErr)?;
+        let [msb, lsb, crc] = buf;
+
+        if Self::crc8(&[msb, lsb]) != crc {
+            return Err(ErrorKind::CrcErr);
+        }
+
+        // Mask off the two status bits in the LSB before combining.
+        Ok(((msb as u16) << 8) | (lsb & 0xFC) as u16)
+    }
+
+    /// Perform a humidity + temperature read cycle and return the `Measure`.
+    pub fn read(&mut self) -> Result<Measure> {
+        let raw_humidity = self.measure_raw(HTU21D_TRIGGER_HUMIDITY)? as f32;
+        let raw_temperature = self.measure_raw(HTU21D_TRIGGER_TEMPERATURE)? as f32;
+
+        let temperature = -46.85 + 175.72 * raw_temperature / 65536.0;
+        let humidity = (-6.0 + 125.0 * raw_humidity / 65536.0).clamp(0.0, 100.0);
+
+        Ok(Measure::new(humidity, temperature))
+    }
+}
@@ -1,29 +1,49 @@
-pub use procfs::Meminfo;
-
 use crate::{ErrorKind, Result};
 use interfaces::{Interface, Kind};
-use procfs::{process::all_processes, KernelStats};
+use procfs::{diskstats, process::all_processes};
 use std::{
+    collections::HashMap,
     fmt::{self, Display, Formatter},
-    fs::File,
-    io::Read,
+    fs,
     net::IpAddr,
-    path::PathBuf,
-    process::Command,
+    path::{Path, PathBuf},
+    time::Instant,
 };
+use sysinfo::{CpuRefreshKind, MemoryRefreshKind, Networks, RefreshKind, System};
 
 /// Cpu usage and temperature information.
+#[derive(Default)]
 pub struct CpuInfo {
     usage: f32,
     temp: f32,
+    /// Per thermal-zone `(label, °C)` readings.
+    zones: Vec<(String, f32)>,
+    /// Per logical-core usage percentages.
+    per_core: Vec<f64>,
 }
 
-impl Default for CpuInfo {
-    fn default() -> Self {
-        Self {
-            usage: 0.0,
-            temp: 0.0,
-        }
+impl CpuInfo {
+    /// Overall CPU usage percentage.
+    pub fn usage(&self) -> f32 {
+        self.usage
+    }
+
+    /// Per-core usage as a compact `c0 c1 ...` list of rounded percentages.
+    pub fn per_core_summary(&self) -> String {
+        self.per_core
+            .iter()
+            .map(|usage| format!("{:.0}", usage))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Per-zone temperature breakdown as `label: xx.xC` lines.
+    pub fn thermal_breakdown(&self) -> String {
+        self.zones
+            .iter()
+            .map(|(label, temp)| format!("{}: {:.1}C", label, temp))
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 }
 
@@ -35,93 +55,259 @@ impl Display for CpuInfo {
 
 /// Cpu.
 ///
-/// # Fields
-///
-/// - thermal_zone: filesystem path to CPU thermal info
-/// - idle_time: idle time from /proc/stat
-/// - total_time: total time from /proc/stat
-/// - usage: `Cpu` usage since last update
-/// - temp: `Cpu` temperature since last update
-#[derive(Debug, Clone)]
+/// Usage comes from a [`sysinfo::System`] refreshed on every `args.delay` tick
+/// in `cpu_thread`; temperatures are read from the thermal zones rooted at the
+/// `--thermal` path.
 pub struct Cpu {
-    thermal_zone: PathBuf,
-    idle_time: u64,
-    total_time: u64,
+    system: System,
+    thermal: PathBuf,
 }
 
 impl Cpu {
-    /// Construct `Cpu` with the given `thermal_zone` path.
-    pub fn new(thermal_zone: &str) -> Result<Self> {
-        // Retrieve current idle and total times.
-        let (idle_time, total_time) = Cpu::get_times()?;
+    /// Construct `Cpu` reading thermal zones rooted at `thermal`.
+    ///
+    /// `thermal` may be a single `.../temp` file (the historical default) or a
+    /// directory such as `/sys/class/thermal` scanned for every
+    /// `thermal_zone*/temp` entry.
+    pub fn new(thermal: &str) -> Result<Self> {
+        let system = System::new_with_specifics(
+            RefreshKind::new().with_cpu(CpuRefreshKind::new().with_cpu_usage()),
+        );
 
         Ok(Self {
-            thermal_zone: PathBuf::from(thermal_zone),
-            idle_time,
-            total_time,
+            system,
+            thermal: PathBuf::from(thermal),
         })
     }
 
-    /// Return time information from `/proc/stat` on Linux filesystem as `(<cpu_idle>, <cpu_total>)`.
-    fn get_times() -> Result<(u64, u64)> {
-        // Read /proc/stat information and retrieve `cpu` row.
-        let cpu = KernelStats::new().map_err(ErrorKind::ProcFsErr)?.total;
+    /// Update and return overall usage, per-core usage and the per-zone
+    /// temperatures (hottest exposed through [`CpuInfo`]'s `Display`).
+    pub fn info(&mut self) -> Result<CpuInfo> {
+        // Refresh once: sysinfo derives usage from the delta between two
+        // consecutive refreshes, so a second refresh here would reset the
+        // per-core baseline and zero the readings. Read both the global and
+        // per-core figures from this single refresh.
+        self.system.refresh_cpu_usage();
 
-        // Calculate the total time.
-        Ok((
-            cpu.idle,
-            cpu.user
-                + cpu.nice
-                + cpu.system
-                + cpu.idle
-                + cpu.iowait.unwrap_or(0)
-                + cpu.irq.unwrap_or(0)
-                + cpu.softirq.unwrap_or(0),
-        ))
+        let usage = self.system.global_cpu_info().cpu_usage();
+        let per_core = self
+            .system
+            .cpus()
+            .iter()
+            .map(|cpu| cpu.cpu_usage() as f64)
+            .collect();
+        let zones = read_thermal_zones(&self.thermal);
+        let temp = zones
+            .iter()
+            .map(|(_, temp)| *temp)
+            .fold(0.0_f32, f32::max);
+
+        Ok(CpuInfo {
+            usage,
+            temp,
+            zones,
+            per_core,
+        })
     }
+}
 
-    /// Return `Cpu` package temperature in *Celsius degrees*.
-    pub fn temp(&mut self) -> Result<f32> {
-        let mut temp = String::new();
+/// Read thermal-zone temperatures rooted at `base`.
+///
+/// When `base` is a directory every `thermal_zone*/temp` entry is scanned,
+/// labelled with the sibling `type` file; when it is a single `temp` file only
+/// that zone is read.
+fn read_thermal_zones(base: &Path) -> Vec<(String, f32)> {
+    let mut zones = Vec::new();
 
-        let mut file = File::open(&self.thermal_zone)
-            .map_err(|err| ErrorKind::FileOpenErr(self.thermal_zone.to_owned(), err))?;
-        file.read_to_string(&mut temp)
-            .map_err(|err| ErrorKind::FileReadErr(self.thermal_zone.to_owned(), err))?;
+    if base.is_dir() {
+        let mut paths: Vec<PathBuf> = match fs::read_dir(base) {
+            Ok(entries) => entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.file_name()
+                        .and_then(|name| name.to_str())
+                        .map(|name| name.starts_with("thermal_zone"))
+                        .unwrap_or(false)
+                })
+                .collect(),
+            Err(_) => return zones,
+        };
+        paths.sort();
 
-        // Safe to unwrap here, guaranteed to have correct format.
-        Ok(temp.trim().parse::<f32>().unwrap() / 1000.0)
+        for zone in paths {
+            if let Some(reading) = read_zone(&zone.join("temp"), &zone.join("type")) {
+                zones.push(reading);
+            }
+        }
+    } else {
+        let label = base
+            .parent()
+            .map(|parent| parent.join("type"))
+            .unwrap_or_default();
+        if let Some(reading) = read_zone(base, &label) {
+            zones.push(reading);
+        }
     }
 
-    /// Return `Cpu` overall percentage usage.
-    pub fn usage(&mut self) -> Result<f32> {
-        let (idle_time, total_time) = Cpu::get_times()?;
+    zones
+}
+
+/// Read a single `temp` file (millidegrees) and its optional `type` label.
+fn read_zone(temp: &Path, label: &Path) -> Option<(String, f32)> {
+    let millidegrees: f32 = fs::read_to_string(temp).ok()?.trim().parse().ok()?;
+    let label = fs::read_to_string(label)
+        .map(|content| content.trim().to_string())
+        .unwrap_or_else(|_| "cpu".to_string());
 
-        // Total CPU usage ([0-100]%).
-        let usage = (1.0
-            - (idle_time - self.idle_time) as f32 / (total_time - self.total_time) as f32)
-            * 100.0;
+    Some((label, millidegrees / 1000.0))
+}
 
-        // Update values.
-        self.total_time = total_time;
-        self.idle_time = idle_time;
+/// A single `hwmon` temperature reading.
+pub struct Component {
+    /// Human readable label (from `tempN_label`, falling back to the chip name).
+    pub label: String,
+    /// Current temperature in *Celsius degrees*.
+    pub temperature: f32,
+    /// Optional maximum temperature (`tempN_max`).
+    pub max: Option<f32>,
+    /// Optional critical temperature (`tempN_crit`).
+    pub critical: Option<f32>,
+}
 
-        Ok(usage)
+impl Component {
+    /// Whether the current temperature is at or above the critical threshold.
+    pub fn is_critical(&self) -> bool {
+        self.critical
+            .map(|critical| self.temperature >= critical)
+            .unwrap_or(false)
     }
+}
 
-    /// Update `usage` and `temperature` fields, as well as `Cpu` times.
-    pub fn info(&mut self) -> Result<CpuInfo> {
-        Ok(CpuInfo {
-            usage: self.usage()?,
-            temp: self.temp()?,
+/// Thermal component collector walking `/sys/class/hwmon`.
+pub struct Components {
+    root: PathBuf,
+}
+
+impl Default for Components {
+    fn default() -> Self {
+        Self {
+            root: PathBuf::from("/sys/class/hwmon"),
+        }
+    }
+}
+
+impl Components {
+    /// Construct a `Components` collector scanning the default `hwmon` root.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scan every `hwmonN` directory and return one [`Component`] per
+    /// `tempN_input` entry found.
+    pub fn refresh(&self) -> Vec<Component> {
+        let mut components = Vec::new();
+
+        let mut chips: Vec<PathBuf> = match fs::read_dir(&self.root) {
+            Ok(entries) => entries.flatten().map(|entry| entry.path()).collect(),
+            Err(_) => return components,
+        };
+        chips.sort();
+
+        for chip in chips {
+            let name = fs::read_to_string(chip.join("name"))
+                .map(|content| content.trim().to_string())
+                .unwrap_or_default();
+
+            // Collect the `tempN_input` indices present on this chip.
+            let mut indices: Vec<u32> = match fs::read_dir(&chip) {
+                Ok(entries) => entries
+                    .flatten()
+                    .filter_map(|entry| {
+                        let file = entry.file_name();
+                        let file = file.to_str()?;
+                        file.strip_prefix("temp")?
+                            .strip_suffix("_input")?
+                            .parse()
+                            .ok()
+                    })
+                    .collect(),
+                Err(_) => continue,
+            };
+            indices.sort_unstable();
+
+            for index in indices {
+                let temperature = match read_millidegrees(&chip.join(format!("temp{index}_input")))
+                {
+                    Some(temperature) => temperature,
+                    None => continue,
+                };
+
+                let label = fs::read_to_string(chip.join(format!("temp{index}_label")))
+                    .map(|content| content.trim().to_string())
+                    .ok()
+                    .filter(|label| !label.is_empty())
+                    .unwrap_or_else(|| name.clone());
+
+                components.push(Component {
+                    label,
+                    temperature,
+                    max: read_millidegrees(&chip.join(format!("temp{index}_max"))),
+                    critical: read_millidegrees(&chip.join(format!("temp{index}_crit"))),
+                });
+            }
+        }
+
+        components
+    }
+}
+
+/// Read a `hwmon` millidegree file and convert it to *Celsius degrees*.
+fn read_millidegrees(path: &Path) -> Option<f32> {
+    fs::read_to_string(path)
+        .ok()?
+        .trim()
+        .parse::<f32>()
+        .ok()
+        .map(|millidegrees| millidegrees / 1000.0)
+}
+
+/// Memory (RAM) information, backed by `sysinfo`.
+pub struct Meminfo {
+    /// Total memory in bytes.
+    pub mem_total: u64,
+    /// Free memory in bytes.
+    pub mem_free: u64,
+    /// `sysinfo::System` owned by this struct (separate from the one in [`Cpu`]),
+    /// refreshed in place on every [`refresh`](Self::refresh).
+    system: System,
+}
+
+impl Meminfo {
+    /// Read current memory information from `sysinfo`.
+    pub fn new() -> Result<Self> {
+        let system = System::new_with_specifics(
+            RefreshKind::new().with_memory(MemoryRefreshKind::new().with_ram()),
+        );
+
+        Ok(Self {
+            mem_total: system.total_memory(),
+            mem_free: system.free_memory(),
+            system,
         })
     }
+
+    /// Refresh RAM figures in place, reusing this struct's `sysinfo::System`.
+    pub fn refresh(&mut self) {
+        self.system.refresh_memory();
+        self.mem_total = self.system.total_memory();
+        self.mem_free = self.system.free_memory();
+    }
 }
 
 /// Memory info values expressed as percentage.
 pub trait MeminfoPerc {
-    /// Convert absolute *kB* value (as found in `/proc/meminfo`) to percentage with respect to
-    /// total memory.
+    /// Convert an absolute byte value to a percentage with respect to total memory.
     fn percentage(&self, value: u64) -> f32;
 
     /// Return used memory percentage.
@@ -132,7 +318,7 @@ pub trait MeminfoPerc {
 }
 
 impl MeminfoPerc for Meminfo {
-    /// Convert absolute kB value to percentage with respect to total memory.
+    /// Convert an absolute byte value to a percentage with respect to total memory.
     fn percentage(&self, value: u64) -> f32 {
         ((value as f64 / self.mem_total as f64) * 100.0) as f32
     }
@@ -148,6 +334,114 @@ impl MeminfoPerc for Meminfo {
     }
 }
 
+/// Download/upload byte and packet rates of a network interface.
+#[derive(Default)]
+pub struct NetRate {
+    /// Receive rate in bytes per second.
+    pub rx_bytes_per_s: f64,
+    /// Transmit rate in bytes per second.
+    pub tx_bytes_per_s: f64,
+    /// Receive rate in packets per second.
+    pub rx_packets_per_s: f64,
+    /// Transmit rate in packets per second.
+    pub tx_packets_per_s: f64,
+}
+
+impl NetRate {
+    /// Receive/transmit packet rates as `↓ Np/s ↑ Mp/s`.
+    pub fn packets(&self) -> String {
+        format!(
+            "\u{2193} {:.0}p/s \u{2191} {:.0}p/s",
+            self.rx_packets_per_s, self.tx_packets_per_s,
+        )
+    }
+}
+
+impl Display for NetRate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "\u{2193} {}/s \u{2191} {}/s",
+            human_readable(self.rx_bytes_per_s as u64),
+            human_readable(self.tx_bytes_per_s as u64),
+        )
+    }
+}
+
+/// Network throughput sampler.
+///
+/// Retains the previous cumulative RX/TX counters and the sampling instant
+/// between [`Net::rates`] calls, deriving the rate from the counter delta over
+/// the elapsed time.
+pub struct Net {
+    networks: Networks,
+    interface: String,
+    counters: NetCounters,
+    last: Instant,
+}
+
+/// Cumulative RX/TX byte and packet counters of an interface.
+#[derive(Default)]
+struct NetCounters {
+    rx_bytes: u64,
+    tx_bytes: u64,
+    rx_packets: u64,
+    tx_packets: u64,
+}
+
+impl Net {
+    /// Construct `Net` tracking the given `interface`.
+    pub fn new(interface: &str) -> Self {
+        let networks = Networks::new_with_refreshed_list();
+        let counters = counters(&networks, interface);
+
+        Self {
+            networks,
+            interface: interface.to_string(),
+            counters,
+            last: Instant::now(),
+        }
+    }
+
+    /// Sample the interface and return the RX/TX byte and packet rates since the
+    /// last call.
+    pub fn rates(&mut self) -> NetRate {
+        self.networks.refresh();
+        let counters = counters(&self.networks, &self.interface);
+        let elapsed = self.last.elapsed().as_secs_f64().max(f64::EPSILON);
+
+        let rate = NetRate {
+            rx_bytes_per_s: counters.rx_bytes.saturating_sub(self.counters.rx_bytes) as f64
+                / elapsed,
+            tx_bytes_per_s: counters.tx_bytes.saturating_sub(self.counters.tx_bytes) as f64
+                / elapsed,
+            rx_packets_per_s: counters.rx_packets.saturating_sub(self.counters.rx_packets) as f64
+                / elapsed,
+            tx_packets_per_s: counters.tx_packets.saturating_sub(self.counters.tx_packets) as f64
+                / elapsed,
+        };
+
+        self.counters = counters;
+        self.last = Instant::now();
+
+        rate
+    }
+}
+
+/// Return the cumulative byte and packet counters of `interface`.
+fn counters(networks: &Networks, interface: &str) -> NetCounters {
+    networks
+        .iter()
+        .find(|(name, _)| name.as_str() == interface)
+        .map(|(_, data)| NetCounters {
+            rx_bytes: data.total_received(),
+            tx_bytes: data.total_transmitted(),
+            rx_packets: data.total_packets_received(),
+            tx_packets: data.total_packets_transmitted(),
+        })
+        .unwrap_or_default()
+}
+
 pub trait InterfaceIPv4 {
     /// Return **IPv4** Address of given interface if present, None otherwhise.
     fn local_ipv4(&self) -> Option<IpAddr>;
@@ -168,21 +462,242 @@ impl InterfaceIPv4 for Interface {
     }
 }
 
-// TODO: remove dependency to other shell commands.
-/// Return free disk space in *human readable format*.
+/// Free/total space and live I/O throughput of a mounted filesystem.
+pub struct Disk {
+    /// Mount point.
+    pub mount: String,
+    /// Available space in bytes.
+    pub available: u64,
+    /// Total space in bytes.
+    pub total: u64,
+    /// Read throughput in bytes per second.
+    pub read_bytes_per_s: f64,
+    /// Write throughput in bytes per second.
+    pub write_bytes_per_s: f64,
+}
+
+impl Display for Disk {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}/{} \u{2193}{}/s \u{2191}{}/s",
+            self.mount,
+            human_readable(self.available),
+            human_readable(self.total),
+            human_readable(self.read_bytes_per_s as u64),
+            human_readable(self.write_bytes_per_s as u64),
+        )
+    }
+}
+
+/// Disk collector reporting space and throughput without spawning `df`.
+///
+/// Space comes from the mounted filesystems; throughput is derived from the
+/// sector counters in `/proc/diskstats` (sectors × 512 / elapsed) sampled
+/// between [`Disks::refresh`] calls.
+pub struct Disks {
+    disks: sysinfo::Disks,
+    prev: HashMap<String, (u64, u64)>,
+    last: Instant,
+}
+
+impl Default for Disks {
+    fn default() -> Self {
+        Self {
+            disks: sysinfo::Disks::new_with_refreshed_list(),
+            prev: read_diskstats(),
+            last: Instant::now(),
+        }
+    }
+}
+
+impl Disks {
+    /// Construct a `Disks` collector with an initial counter snapshot.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Refresh and return the current per-mount space and I/O rates.
+    pub fn refresh(&mut self) -> Vec<Disk> {
+        self.disks.refresh();
+        let current = read_diskstats();
+        let elapsed = self.last.elapsed().as_secs_f64().max(f64::EPSILON);
+
+        let disks = self
+            .disks
+            .iter()
+            .map(|disk| {
+                let device = device_name(disk.name().to_string_lossy().as_ref());
+                let (read_bytes_per_s, write_bytes_per_s) = match (
+                    self.prev.get(&device),
+                    current.get(&device),
+                ) {
+                    (Some(&(prev_r, prev_w)), Some(&(cur_r, cur_w))) => (
+                        cur_r.saturating_sub(prev_r) as f64 * 512.0 / elapsed,
+                        cur_w.saturating_sub(prev_w) as f64 * 512.0 / elapsed,
+                    ),
+                    _ => (0.0, 0.0),
+                };
+
+                Disk {
+                    mount: disk.mount_point().to_string_lossy().to_string(),
+                    available: disk.available_space(),
+                    total: disk.total_space(),
+                    read_bytes_per_s,
+                    write_bytes_per_s,
+                }
+            })
+            .collect();
+
+        self.prev = current;
+        self.last = Instant::now();
+
+        disks
+    }
+}
+
+/// Basename of a block device path (e.g. `/dev/mmcblk0p2` → `mmcblk0p2`).
+fn device_name(path: &str) -> String {
+    path.rsplit('/').next().unwrap_or(path).to_string()
+}
+
+/// Read `(sectors_read, sectors_written)` per device from `/proc/diskstats`.
+fn read_diskstats() -> HashMap<String, (u64, u64)> {
+    let mut stats = HashMap::new();
+    if let Ok(entries) = diskstats() {
+        for entry in entries {
+            stats.insert(entry.name, (entry.sectors_read, entry.sectors_written));
+        }
+    }
+
+    stats
+}
+
+/// Format a byte count using binary (IEC) units, mirroring `df -h`.
+pub fn human_readable(bytes: u64) -> String {
+    const UNITS: [&str; 6] = ["B", "K", "M", "G", "T", "P"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{}{}", bytes, UNITS[unit])
+    } else {
+        format!("{:.1}{}", value, UNITS[unit])
+    }
+}
+
+/// Available space of the `/` mount in human-readable form, backed by `sysinfo`.
+///
+/// Kept as the portable replacement for the original `df` subprocess helper;
+/// [`Disks`] supersedes it for the richer per-mount DISK page, but the overview
+/// still builds on this single-value surface.
 pub fn disk_free() -> Result<String> {
-    // Spawn `df` command with human readable parameter `-h` on `/` and collect output.
-    let output = Command::new("df")
-        .args(["-h", "--output=avail", "/"])
-        .output()
-        .map_err(|_| "unable to execute `df` command")?;
-
-    Ok(String::from_utf8(output.stdout)
-        .unwrap() // Safe to unwrap, command output is guaranteed to be UTF-8.
-        .split('\n')
-        .collect::<Vec<&str>>()[1]
-        .trim_start()
-        .to_string())
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+
+    disks
+        .iter()
+        .find(|disk| disk.mount_point() == Path::new("/"))
+        .map(|disk| human_readable(disk.available_space()))
+        .ok_or_else(|| ErrorKind::Other(String::from("root mount '/' not found")))
+}
+
+/// A single process resource sample.
+pub struct Process {
+    /// Process ID.
+    pub pid: i32,
+    /// Executable name (`comm`).
+    pub name: String,
+    /// CPU usage percentage over the last refresh interval.
+    pub cpu: f32,
+    /// Resident set size in bytes.
+    pub rss: u64,
+}
+
+impl Display for Process {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}[{}] {:.1}% {}",
+            self.name,
+            self.pid,
+            self.cpu,
+            human_readable(self.rss),
+        )
+    }
+}
+
+/// Process collector computing per-process CPU usage and memory.
+///
+/// Samples each process's `utime + stime` from `/proc/<pid>/stat` and diffs it
+/// against the previous sample over the elapsed interval to derive %CPU.
+pub struct Processes {
+    prev: HashMap<i32, u64>,
+    last: Instant,
+    ticks_per_sec: u64,
+    page_size: u64,
+}
+
+impl Default for Processes {
+    fn default() -> Self {
+        Self {
+            prev: HashMap::new(),
+            last: Instant::now(),
+            ticks_per_sec: procfs::ticks_per_second(),
+            page_size: procfs::page_size(),
+        }
+    }
+}
+
+impl Processes {
+    /// Construct a `Processes` collector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the top `n` processes ordered by CPU usage, descending.
+    pub fn top(&mut self, n: usize) -> Result<Vec<Process>> {
+        let elapsed = self.last.elapsed().as_secs_f64().max(f64::EPSILON);
+
+        let mut current = HashMap::new();
+        let mut processes = Vec::new();
+        for proc in all_processes().map_err(ErrorKind::ProcFsErr)? {
+            let stat = match proc.and_then(|proc| proc.stat()) {
+                Ok(stat) => stat,
+                // The process may have exited between enumeration and reading.
+                Err(_) => continue,
+            };
+
+            let ticks = stat.utime + stat.stime;
+            current.insert(stat.pid, ticks);
+
+            // A freshly seen process has no baseline, so it reads as idle.
+            let previous = self.prev.get(&stat.pid).copied().unwrap_or(ticks);
+            let cpu =
+                (ticks.saturating_sub(previous) as f64 / self.ticks_per_sec as f64 / elapsed
+                    * 100.0) as f32;
+
+            processes.push(Process {
+                pid: stat.pid,
+                name: stat.comm,
+                cpu,
+                rss: stat.rss as u64 * self.page_size,
+            });
+        }
+
+        self.prev = current;
+        self.last = Instant::now();
+
+        processes
+            .sort_by(|a, b| b.cpu.partial_cmp(&a.cpu).unwrap_or(std::cmp::Ordering::Equal));
+        processes.truncate(n);
+
+        Ok(processes)
+    }
 }
 
 /// Check for running process returning true if the process is running, false if not.